@@ -96,8 +96,13 @@ impl DerivationScheme {
 /// * make sure the user remembers the mnemonics string;
 ///
 #[wasm_bindgen]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct Entropy(bip39::Entropy);
+impl std::fmt::Debug for Entropy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Entropy([redacted])")
+    }
+}
 #[wasm_bindgen]
 impl Entropy {
     /// retrieve the initial entropy of a wallet from the given
@@ -145,8 +150,16 @@ impl Entropy {
 ///   with it;
 ///
 #[wasm_bindgen]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct PrivateKey(hdwallet::XPrv);
+// like the `Entropy`, the private key is never printed by `Debug` so it
+// does not end up in logs or panic messages. Use `to_hex` or `to_bytes`
+// explicitly instead.
+impl std::fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "PrivateKey([redacted])")
+    }
+}
 #[wasm_bindgen]
 impl PrivateKey {
     /// create a new private key from a given Entropy
@@ -168,6 +181,35 @@ impl PrivateKey {
         format!("{}", self.0)
     }
 
+    /// retrieve a private key from its raw bytes.
+    ///
+    /// Expects exactly 96 bytes: the 64 bytes of the extended ed25519
+    /// secret key followed by the 32 bytes of the chain code.
+    ///
+    /// # Errors
+    ///
+    /// fails if `bytes` is not exactly 96 bytes long, or if the secret
+    /// scalar is not properly clamped (as required by BIP32-Ed25519),
+    /// which is the case of corrupted keys.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PrivateKey, JsValue> {
+        if bytes.len() != hdwallet::XPRV_SIZE {
+            return Err(JsValue::from_str(&format!(
+                "Invalid private key size, expected {} bytes but received {} bytes",
+                hdwallet::XPRV_SIZE,
+                bytes.len(),
+            )));
+        }
+        let mut xprv_bytes = [0; hdwallet::XPRV_SIZE];
+        xprv_bytes.copy_from_slice(bytes);
+        hdwallet::XPrv::from_bytes_verified(xprv_bytes)
+            .map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+            .map(PrivateKey)
+    }
+    /// get the raw bytes of the private key (see `from_bytes` for the layout)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.as_ref().to_vec()
+    }
+
     /// get the public key associated to this private key
     pub fn public(&self) -> PublicKey {
         PublicKey(self.0.public())
@@ -229,6 +271,20 @@ impl PublicKey {
         format!("{}", self.0)
     }
 
+    /// retrieve a public key from its raw bytes.
+    ///
+    /// Expects exactly 64 bytes: the 32 bytes of the ed25519 public key
+    /// followed by the 32 bytes of the chain code.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKey, JsValue> {
+        hdwallet::XPub::from_slice(bytes)
+            .map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+            .map(PublicKey)
+    }
+    /// get the raw bytes of the public key (see `from_bytes` for the layout)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.as_ref().to_vec()
+    }
+
     pub fn verify(&self, data: &[u8], signature: &Signature) -> bool {
         self.0.verify(data, &signature.0)
     }
@@ -277,6 +333,25 @@ impl PublicKey {
             blockchain_settings.protocol_magic.into(),
         ))
     }
+
+    /// get the bootstrap era address embedding the given derivation path
+    /// payload (the encrypted derivation path of a Daedalus address).
+    pub fn bootstrap_era_address_with_payload(
+        &self,
+        blockchain_settings: &BlockchainSettings,
+        payload: &[u8],
+    ) -> Address {
+        let hdap = hdpayload::HDAddressPayload::from_bytes(payload);
+        let attributes = address::Attributes::new_bootstrap_era(
+            Some(hdap),
+            blockchain_settings.protocol_magic.into(),
+        );
+        Address(address::ExtendedAddr::new(
+            address::AddrType::ATPubKey,
+            address::SpendingData::PubKeyASD(self.0.clone()),
+            attributes,
+        ))
+    }
 }
 
 #[wasm_bindgen]
@@ -301,6 +376,101 @@ impl Address {
             Err(err) => false,
         }
     }
+
+    /// serialize the address into its CBOR representation (the bytes
+    /// the base58 string is encoding).
+    pub fn to_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        cbor!(&self.0).map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+    }
+
+    /// retrieve the address from its CBOR representation.
+    ///
+    /// This will fail if the CRC32 embedded in the address does not match
+    /// or if there are trailing bytes after the address.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Address, JsValue> {
+        let mut raw = cbor_event::de::Deserializer::from(std::io::Cursor::new(bytes));
+        raw.deserialize_complete()
+            .map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+            .map(Address)
+    }
+
+    /// check the address belongs to the blockchain of the given settings.
+    ///
+    /// Use it to prevent sending funds to an address of another network
+    /// (e.g. a testnet address while on mainnet). This compares the
+    /// network magic embedded in the address (none on mainnet).
+    pub fn is_for_blockchain(&self, blockchain_settings: &BlockchainSettings) -> bool {
+        let network_magic: config::NetworkMagic = blockchain_settings.protocol_magic.into();
+        self.0.attributes.network_magic == network_magic
+    }
+
+    /// check the address has been generated from the given public key.
+    ///
+    /// The address is rebuilt from the public key with this address' own
+    /// attributes (derivation path payload, stake distribution and
+    /// network magic) and compared to this address.
+    pub fn identical_with_pubkey(&self, key: &PublicKey) -> bool {
+        self.identical_with_spending_data(address::SpendingData::PubKeyASD(key.0.clone()))
+    }
+
+    /// get a JSON object describing the content of the address (address
+    /// type, stake distribution, network magic, presence of a derivation
+    /// path...). Useful to display what kind of address the user has entered.
+    ///
+    /// See `AddressInfo` for the fields of the returned object.
+    pub fn info(&self) -> Result<JsValue, JsValue> {
+        let address_type = match self.0.addr_type {
+            address::AddrType::ATPubKey => "public_key",
+            address::AddrType::ATScript => "script",
+            address::AddrType::ATRedeem => "redeem",
+        };
+        let (is_bootstrap_era, stakeholder_id) = match &self.0.attributes.stake_distribution {
+            address::StakeDistribution::BootstrapEraDistr => (true, None),
+            address::StakeDistribution::SingleKeyDistr(id) => (false, Some(format!("{}", id))),
+        };
+        let network_magic = match self.0.attributes.network_magic {
+            config::NetworkMagic::NoMagic => None,
+            config::NetworkMagic::Magic(magic) => Some(magic),
+        };
+        let info = AddressInfo {
+            address: self.to_base58(),
+            address_type: address_type.to_owned(),
+            is_bootstrap_era,
+            stakeholder_id,
+            has_derivation_path: self.0.attributes.derivation_path.is_some(),
+            network_magic,
+        };
+        JsValue::from_serde(&info).map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+    }
+}
+
+impl Address {
+    fn identical_with_spending_data(&self, spending_data: address::SpendingData) -> bool {
+        let address = address::ExtendedAddr::new(
+            self.0.addr_type.clone(),
+            spending_data,
+            self.0.attributes.clone(),
+        );
+        self.0 == address
+    }
+}
+
+/// JSON schema of the object returned by `Address::info`.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct AddressInfo {
+    /// the base58 encoded address
+    pub address: String,
+    /// one of `public_key`, `script` or `redeem`
+    pub address_type: String,
+    /// the address uses the bootstrap era stake distribution
+    pub is_bootstrap_era: bool,
+    /// hex encoded stakeholder id of a single key stake distribution,
+    /// `null` for bootstrap era addresses
+    pub stakeholder_id: Option<String>,
+    /// the address embeds a derivation path payload
+    pub has_derivation_path: bool,
+    /// the network magic, `null` for mainnet addresses
+    pub network_magic: Option<u32>,
 }
 
 #[wasm_bindgen]
@@ -537,6 +707,36 @@ impl Bip44ChainPublic {
             .derive(self.derivation_scheme, index.0)
     }
 
+    /// derive the bootstrap era addresses of the indices `[start, start + count)`
+    ///
+    /// This is useful when scanning for the used addresses of a wallet
+    /// (address discovery) as it saves one call per address. The result is
+    /// an array of base58 encoded addresses, in the order of the indices.
+    ///
+    /// # Errors
+    ///
+    /// fails if `start + count` goes beyond the soft derivation indices
+    /// (0x80000000).
+    pub fn bootstrap_era_addresses(
+        &self,
+        blockchain_settings: &BlockchainSettings,
+        start: AddressKeyIndex,
+        count: u32,
+    ) -> Result<JsValue, JsValue> {
+        if start.0 as u64 + count as u64 > bip44::BIP44_SOFT_UPPER_BOUND as u64 {
+            return Err(JsValue::from(
+                "index out of bound. Expected start + count to be at most 0x80000000",
+            ));
+        }
+        let addresses = (start.0..start.0 + count)
+            .map(|index| {
+                self.address_key(AddressKeyIndex(index))
+                    .map(|key| key.bootstrap_era_address(blockchain_settings))
+            })
+            .collect::<Result<Vec<Address>, JsValue>>()?;
+        JsValue::from_serde(&addresses).map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+    }
+
     pub fn key(&self) -> PublicKey {
         self.key.clone()
     }
@@ -771,6 +971,19 @@ impl Coin {
             .map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
             .map(Coin)
     }
+
+    /// subtract `other` from this coin value.
+    ///
+    /// # Errors
+    ///
+    /// fails if `other` is greater than this value (coins cannot be negative).
+    pub fn sub(&self, other: &Coin) -> Result<Coin, JsValue> {
+        use std::ops::Sub;
+        self.0
+            .sub(other.0)
+            .map_err(|e| JsValue::from_str(&format! {"{:?}", e}))
+            .map(Coin)
+    }
 }
 
 #[wasm_bindgen]
@@ -1098,6 +1311,32 @@ impl Witness {
 
         Witness(witness)
     }
+
+    /// verify the witness' signature is valid for the given transaction
+    pub fn verify_transaction(
+        &self,
+        blockchain_settings: &BlockchainSettings,
+        transaction: &Transaction,
+    ) -> bool {
+        self.0
+            .verify_tx(blockchain_settings.protocol_magic, &transaction.0)
+    }
+
+    /// verify the witness' public key is the one of the given address
+    /// (i.e. the witness can unlock the funds sent to this address)
+    pub fn verify_address(&self, address: &Address) -> bool {
+        let spending_data = match &self.0 {
+            tx::TxInWitness::PkWitness(key, _) => address::SpendingData::PubKeyASD(key.clone()),
+            tx::TxInWitness::RedeemWitness(key, _) => {
+                address::SpendingData::RedeemASD(key.clone())
+            }
+            // script witnesses cannot be created from this API and their
+            // validator script is not something we know how to rebuild an
+            // address from: never consider them as unlocking an address.
+            tx::TxInWitness::ScriptWitness(..) => return false,
+        };
+        address.identical_with_spending_data(spending_data)
+    }
 }
 
 #[wasm_bindgen]
@@ -0,0 +1,157 @@
+#![cfg(target_arch = "wasm32")]
+
+extern crate cardano_wallet;
+extern crate serde_json;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_test;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+use cardano_wallet::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ADDRESS: &'static str = "Ae2tdPwUPEZCEhYAUVU7evPfQCJjyuwM6n81x6hSjU9TBMSy2YwZEVydssL";
+const TESTNET_PROTOCOL_MAGIC: u32 = 1097911063;
+
+const PAYLOAD_PUBLIC_KEY: [u8; 64] = [
+    28, 12, 58, 225, 130, 94, 144, 182, 221, 218, 63, 64, 161, 34, 192, 7, 225, 0, 142, 131, 178,
+    225, 2, 193, 66, 186, 239, 183, 33, 215, 44, 26, 93, 54, 97, 222, 185, 6, 79, 45, 14, 3, 254,
+    133, 214, 128, 112, 178, 254, 51, 180, 145, 96, 89, 101, 142, 40, 172, 127, 127, 145, 202, 75,
+    18,
+];
+const PAYLOAD: [u8; 20] = [
+    229, 123, 216, 139, 186, 31, 136, 170, 141, 206, 193, 201, 206, 53, 33, 116, 160, 227, 158, 62,
+];
+const PAYLOAD_ADDRESS: [u8; 66] = [
+    130, 216, 24, 88, 56, 131, 88, 28, 162, 248, 66, 62, 170, 11, 93, 77, 216, 98, 8, 209, 204, 187,
+    31, 223, 121, 177, 156, 148, 244, 180, 194, 111, 27, 69, 192, 6, 161, 1, 85, 84, 229, 123, 216,
+    139, 186, 31, 136, 170, 141, 206, 193, 201, 206, 53, 33, 116, 160, 227, 158, 62, 0, 26, 101,
+    245, 21, 213,
+];
+
+fn testnet_settings() -> BlockchainSettings {
+    let mut settings = HashMap::new();
+    settings.insert("protocol_magic", TESTNET_PROTOCOL_MAGIC);
+    BlockchainSettings::from_json(JsValue::from_serde(&settings).unwrap()).unwrap()
+}
+
+fn testnet_address() -> Address {
+    let entropy = Entropy::from_english_mnemonics(
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ).unwrap();
+    let public_key = PrivateKey::new(&entropy, "").public();
+    public_key.bootstrap_era_address(&testnet_settings())
+}
+
+fn info(address: &Address) -> serde_json::Value {
+    address.info().unwrap().into_serde().unwrap()
+}
+
+#[wasm_bindgen_test]
+fn address_bytes_round_trip() {
+    let address = Address::from_base58(ADDRESS).unwrap();
+    let bytes = address.to_bytes().unwrap();
+    let decoded = Address::from_bytes(&bytes).unwrap();
+
+    assert_eq!(address, decoded);
+}
+
+#[wasm_bindgen_test]
+fn address_from_bytes_invalid_crc() {
+    let address = Address::from_base58(ADDRESS).unwrap();
+    let mut bytes = address.to_bytes().unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0x01;
+
+    assert!(Address::from_bytes(&bytes).is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_from_bytes_trailing_bytes() {
+    let address = Address::from_base58(ADDRESS).unwrap();
+    let mut bytes = address.to_bytes().unwrap();
+    bytes.push(0x00);
+
+    assert!(Address::from_bytes(&bytes).is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_base58_round_trip() {
+    let address = Address::from_base58(ADDRESS).unwrap();
+
+    assert_eq!(ADDRESS, address.to_base58());
+}
+
+#[wasm_bindgen_test]
+fn address_base58_invalid_character() {
+    // `0`, `O`, `I` and `l` are not part of the bitcoin base58 alphabet
+    const INVALID: &'static str = "Ae2tdPwUPEZCEhYAUVU7evPfQCJjyuwM6n81x6hSjU9TBMSy2YwZEVydss0";
+
+    assert!(!Address::is_valid(INVALID));
+    assert!(Address::from_base58(INVALID).is_err());
+}
+
+#[wasm_bindgen_test]
+fn public_key_to_address_with_payload() {
+    let settings = BlockchainSettings::mainnet();
+    let public_key = PublicKey::from_bytes(&PAYLOAD_PUBLIC_KEY).unwrap();
+    let address = public_key.bootstrap_era_address_with_payload(&settings, &PAYLOAD);
+
+    assert_eq!(&PAYLOAD_ADDRESS[..], address.to_bytes().unwrap().as_slice());
+}
+
+#[wasm_bindgen_test]
+fn address_is_for_blockchain() {
+    let mainnet = BlockchainSettings::mainnet();
+    let testnet = testnet_settings();
+
+    let address = Address::from_base58(ADDRESS).unwrap();
+    assert!(address.is_for_blockchain(&mainnet));
+    assert!(!address.is_for_blockchain(&testnet));
+
+    let address = testnet_address();
+    assert!(address.is_for_blockchain(&testnet));
+    assert!(!address.is_for_blockchain(&mainnet));
+}
+
+#[wasm_bindgen_test]
+fn address_info() {
+    let address = Address::from_base58(ADDRESS).unwrap();
+    let info = info(&address);
+
+    assert_eq!(info["address"], ADDRESS);
+    assert_eq!(info["address_type"], "public_key");
+    assert_eq!(info["is_bootstrap_era"], true);
+    assert!(info["stakeholder_id"].is_null());
+    assert_eq!(info["has_derivation_path"], false);
+    assert!(info["network_magic"].is_null());
+}
+
+#[wasm_bindgen_test]
+fn address_info_redeem() {
+    const REDEEM_ADDRESS: &'static str =
+        "Ae2tdPwUPEZHFQnrr2dYB4GEQ8WVKspEyrg29pJ3f7qdjzaxjeShEEokF5f";
+    let address = Address::from_base58(REDEEM_ADDRESS).unwrap();
+
+    assert_eq!(info(&address)["address_type"], "redeem");
+}
+
+#[wasm_bindgen_test]
+fn address_info_derivation_path() {
+    let address = Address::from_bytes(&PAYLOAD_ADDRESS).unwrap();
+    let info = info(&address);
+
+    assert_eq!(info["address_type"], "public_key");
+    assert_eq!(info["has_derivation_path"], true);
+    assert!(info["network_magic"].is_null());
+}
+
+#[wasm_bindgen_test]
+fn address_info_network_magic() {
+    let info = info(&testnet_address());
+
+    assert_eq!(info["network_magic"], TESTNET_PROTOCOL_MAGIC);
+    assert_eq!(info["has_derivation_path"], false);
+}
@@ -0,0 +1,32 @@
+#![cfg(target_arch = "wasm32")]
+
+extern crate cardano_wallet;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_test;
+use wasm_bindgen_test::*;
+
+use cardano_wallet::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn coin_add_sub() {
+    let a = Coin::from(10, 500).unwrap();
+    let b = Coin::from(3, 999_600).unwrap();
+
+    let sum = a.add(&b).unwrap();
+    assert_eq!(sum.ada(), 14);
+    assert_eq!(sum.lovelace(), 100);
+
+    let diff = sum.sub(&b).unwrap();
+    assert_eq!(diff.ada(), a.ada());
+    assert_eq!(diff.lovelace(), a.lovelace());
+}
+
+#[wasm_bindgen_test]
+fn coin_sub_negative() {
+    let a = Coin::from(1, 0).unwrap();
+    let b = Coin::from(2, 0).unwrap();
+
+    assert!(a.sub(&b).is_err());
+}
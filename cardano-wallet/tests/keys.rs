@@ -0,0 +1,93 @@
+#![cfg(target_arch = "wasm32")]
+
+extern crate cardano_wallet;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_test;
+use wasm_bindgen_test::*;
+
+use cardano_wallet::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const MNEMONICS: &'static str = "crowd captain hungry tray powder motor coast oppose month shed parent mystery torch resemble index";
+const PASSWORD: &'static str = "Cardano Rust for the winners!";
+
+fn private_key() -> PrivateKey {
+    let entropy = Entropy::from_english_mnemonics(MNEMONICS).unwrap();
+    PrivateKey::new(&entropy, PASSWORD)
+}
+
+#[wasm_bindgen_test]
+fn public_key_bytes_round_trip() {
+    let public_key = private_key().public();
+    let bytes = public_key.to_bytes();
+
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(PublicKey::from_bytes(&bytes).unwrap(), public_key);
+}
+
+#[wasm_bindgen_test]
+fn public_key_from_bytes_invalid_length() {
+    let bytes = private_key().public().to_bytes();
+
+    assert!(PublicKey::from_bytes(&bytes[..63]).is_err());
+
+    let mut bytes = bytes;
+    bytes.push(0);
+    assert!(PublicKey::from_bytes(&bytes).is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_identical_with_pubkey() {
+    const PAYLOAD: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+    let settings = BlockchainSettings::mainnet();
+    let public_key = private_key().public();
+    let other_key = private_key()
+        .derive(DerivationScheme::v2(), 0)
+        .public();
+
+    let address = public_key.bootstrap_era_address(&settings);
+    assert!(address.identical_with_pubkey(&public_key));
+    assert!(!address.identical_with_pubkey(&other_key));
+
+    let address = public_key.bootstrap_era_address_with_payload(&settings, &PAYLOAD);
+    assert!(address.identical_with_pubkey(&public_key));
+    assert!(!address.identical_with_pubkey(&other_key));
+}
+
+#[wasm_bindgen_test]
+fn private_key_bytes_round_trip() {
+    let key = private_key();
+    let bytes = key.to_bytes();
+
+    assert_eq!(bytes.len(), 96);
+    assert_eq!(PrivateKey::from_bytes(&bytes).unwrap(), key);
+}
+
+#[wasm_bindgen_test]
+fn private_key_from_bytes_invalid() {
+    let bytes = private_key().to_bytes();
+    assert!(PrivateKey::from_bytes(&bytes[..95]).is_err());
+
+    // the 3 lowest bits of the scalar must be cleared
+    let mut unclamped = bytes.clone();
+    unclamped[0] |= 0b0000_0111;
+    assert!(PrivateKey::from_bytes(&unclamped).is_err());
+
+    // the highest bit of the scalar must be cleared
+    let mut unclamped = bytes;
+    unclamped[31] |= 0b1000_0000;
+    assert!(PrivateKey::from_bytes(&unclamped).is_err());
+}
+
+#[wasm_bindgen_test]
+fn secrets_redacted_in_debug() {
+    let entropy = Entropy::from_english_mnemonics(MNEMONICS).unwrap();
+    let key = private_key();
+
+    let debug = format!("{:?}", key);
+    assert_eq!(debug, "PrivateKey([redacted])");
+    assert!(!debug.contains(&key.to_hex()));
+
+    assert_eq!(format!("{:?}", entropy), "Entropy([redacted])");
+}
@@ -0,0 +1,58 @@
+#![cfg(target_arch = "wasm32")]
+
+extern crate cardano_wallet;
+extern crate wasm_bindgen;
+extern crate wasm_bindgen_test;
+use wasm_bindgen_test::*;
+
+use cardano_wallet::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const MNEMONICS: &'static str = "crowd captain hungry tray powder motor coast oppose month shed parent mystery torch resemble index";
+const PASSWORD: &'static str = "Cardano Rust for the winners!";
+const TXID: &'static str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+fn private_key(index: u32) -> PrivateKey {
+    let entropy = Entropy::from_english_mnemonics(MNEMONICS).unwrap();
+    PrivateKey::new(&entropy, PASSWORD).derive(DerivationScheme::v2(), index)
+}
+
+fn transaction(value: u32) -> Transaction {
+    let settings = BlockchainSettings::mainnet();
+    let address = private_key(0).public().bootstrap_era_address(&settings);
+    let pointer = TxoPointer::new(&TransactionId::from_hex(TXID).unwrap(), 0);
+
+    let mut builder = TransactionBuilder::new();
+    builder
+        .add_input(&pointer, Coin::from(value, 0).unwrap())
+        .unwrap();
+    builder
+        .add_output(&TxOut::new(&address, &Coin::from(value - 1, 0).unwrap()))
+        .unwrap();
+    builder.make_transaction().unwrap()
+}
+
+#[wasm_bindgen_test]
+fn witness_verify_transaction() {
+    let settings = BlockchainSettings::mainnet();
+    let tx = transaction(10);
+    let witness = Witness::new_extended_key(&settings, &private_key(0), &tx.id());
+
+    assert!(witness.verify_transaction(&settings, &tx));
+    assert!(!witness.verify_transaction(&settings, &transaction(11)));
+}
+
+#[wasm_bindgen_test]
+fn witness_verify_address() {
+    let settings = BlockchainSettings::mainnet();
+    let tx = transaction(10);
+    let address = private_key(0).public().bootstrap_era_address(&settings);
+
+    let witness = Witness::new_extended_key(&settings, &private_key(0), &tx.id());
+    assert!(witness.verify_address(&address));
+
+    let witness = Witness::new_extended_key(&settings, &private_key(1), &tx.id());
+    assert!(witness.verify_transaction(&settings, &tx));
+    assert!(!witness.verify_address(&address));
+}
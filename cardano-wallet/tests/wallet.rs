@@ -39,3 +39,38 @@ fn recover_root_key() {
     let entropy = Entropy::from_english_mnemonics(MNEMONICS).unwrap();
     let root_key = Bip44RootPrivateKey::recover(&entropy, PASSWORD).unwrap();
 }
+
+fn chain_public() -> Bip44ChainPublic {
+    let entropy = Entropy::from_english_mnemonics(MNEMONICS).unwrap();
+    let root_key = Bip44RootPrivateKey::recover(&entropy, PASSWORD).unwrap();
+    let account = root_key.bip44_account(AccountIndex::new(0x80000000).unwrap());
+    account.public().bip44_chain(false).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn bootstrap_era_addresses_range() {
+    let settings = BlockchainSettings::mainnet();
+    let chain = chain_public();
+
+    let addresses = chain
+        .bootstrap_era_addresses(&settings, AddressKeyIndex::new(3).unwrap(), 5)
+        .unwrap();
+    let addresses: Vec<String> = addresses.into_serde().unwrap();
+
+    assert_eq!(addresses.len(), 5);
+    for (i, address) in addresses.iter().enumerate() {
+        let key = chain
+            .address_key(AddressKeyIndex::new(3 + i as u32).unwrap())
+            .unwrap();
+        assert_eq!(address, &key.bootstrap_era_address(&settings).to_base58());
+    }
+}
+
+#[wasm_bindgen_test]
+fn bootstrap_era_addresses_out_of_bound() {
+    let settings = BlockchainSettings::mainnet();
+    let chain = chain_public();
+
+    let start = AddressKeyIndex::new(0x7FFFFFFF).unwrap();
+    assert!(chain.bootstrap_era_addresses(&settings, start, 2).is_err());
+}
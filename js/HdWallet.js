@@ -141,6 +141,29 @@ export const publicKeyToAddress = (module, xpub, payload) => {
   return addr;
 };
 
+/**
+ * @param module    - the WASM module that is used for crypto operations
+ * @param seed      - the original seed, needs to be {@link SEED_SIZE}
+ * @param payload   - the derivation path payload to embed in the address
+ * @returns {*}     - returns false if the seed is not of the valid length, or return the address
+ *                    of the root public key (the root private key is not exposed to JS)
+ */
+export const seedToAddress = (module, seed, payload) => {
+  if (seed.length !== SEED_SIZE) { return false; }
+  const bufseed    = newArray(module, seed);
+  const bufpayload = newArray(module, payload);
+  const bufaddr    = newArray0(module, 1024);
+
+  let rs = module.wallet_seed_to_address(bufseed, bufpayload, payload.length, bufaddr);
+  let addr = copyArray(module, bufaddr, rs);
+
+  module.dealloc(bufaddr);
+  module.dealloc(bufpayload);
+  module.dealloc(bufseed);
+
+  return addr;
+};
+
 export const addressGetPayload = (module, address) => {
   const bufaddr    = newArray(module, address);
   const bufpayload = newArray0(module, 1024);
@@ -167,6 +190,7 @@ export default {
   derivePublic: apply(derivePublic, RustModule),
   sign: apply(sign, RustModule),
   publicKeyToAddress: apply(publicKeyToAddress, RustModule),
+  seedToAddress: apply(seedToAddress, RustModule),
   addressGetPayload: apply(addressGetPayload, RustModule),
   SEED_SIZE: SEED_SIZE,
   XPRV_SIZE: XPRV_SIZE,
@@ -21,3 +21,21 @@ describe('Wallet FromSeed', async function () {
         wallet = result.result;
     });
 });
+
+describe('HdWallet seedToAddress', async function () {
+    const PAYLOAD = new Uint8Array([229, 123, 216, 139, 186, 31, 136, 170, 141, 206, 193, 201, 206, 53, 33, 116, 160, 227, 158, 62]);
+
+    before(async () => {
+        await CardanoCrypto.loadRustModule()
+    });
+
+    it("reject invalid seed size", function () {
+        expect(CardanoCrypto.HdWallet.seedToAddress(Array(31).fill(0), PAYLOAD)).equals(false);
+    });
+    it("match the address of the root public key", function () {
+        const xprv = CardanoCrypto.HdWallet.fromSeed(SEED);
+        const xpub = CardanoCrypto.HdWallet.toPublic(xprv);
+        expect(CardanoCrypto.HdWallet.seedToAddress(SEED, PAYLOAD))
+            .deep.equal(CardanoCrypto.HdWallet.publicKeyToAddress(xpub, PAYLOAD));
+    });
+});
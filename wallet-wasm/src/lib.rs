@@ -281,6 +281,19 @@ fn default_network_magic() -> cardano::config::NetworkMagic {
     cardano::config::ProtocolMagic::default().into()
 }
 
+/// cbor encoded bootstrap era address of the given public key, with the
+/// given derivation path payload.
+fn bootstrap_address_bytes(xpub: hdwallet::XPub, payload: Vec<u8>) -> Vec<u8> {
+    let hdap = hdpayload::HDAddressPayload::from_vec(payload);
+
+    let addr_type = address::AddrType::ATPubKey;
+    let sd = address::SpendingData::PubKeyASD(xpub);
+    let attrs = address::Attributes::new_bootstrap_era(Some(hdap), default_network_magic());
+    let ea = address::ExtendedAddr::new(addr_type, sd, attrs);
+
+    cbor!(ea).unwrap()
+}
+
 #[no_mangle]
 pub extern "C" fn wallet_public_to_address(
     xpub_ptr: *const c_uchar,
@@ -291,14 +304,28 @@ pub extern "C" fn wallet_public_to_address(
     let xpub = unsafe { read_xpub(xpub_ptr) };
     let payload = unsafe { read_data(payload_ptr, payload_sz) };
 
-    let hdap = hdpayload::HDAddressPayload::from_vec(payload);
+    let ea_bytes = bootstrap_address_bytes(xpub, payload);
 
-    let addr_type = address::AddrType::ATPubKey;
-    let sd = address::SpendingData::PubKeyASD(xpub.clone());
-    let attrs = address::Attributes::new_bootstrap_era(Some(hdap), default_network_magic());
-    let ea = address::ExtendedAddr::new(addr_type, sd, attrs);
+    unsafe { write_data(&ea_bytes, out) }
 
-    let ea_bytes = cbor!(ea).unwrap();
+    return ea_bytes.len() as u32;
+}
+
+/// one shot version of `wallet_from_seed`, `wallet_to_public` and
+/// `wallet_public_to_address`: the root private key never leaves the
+/// wasm memory.
+#[no_mangle]
+pub extern "C" fn wallet_seed_to_address(
+    seed_ptr: *const c_uchar,
+    payload_ptr: *const c_uchar,
+    payload_sz: usize,
+    out: *mut c_uchar,
+) -> u32 {
+    let seed = unsafe { read_seed(seed_ptr) };
+    let payload = unsafe { read_data(payload_ptr, payload_sz) };
+
+    let xprv = hdwallet::XPrv::generate_from_seed(&seed);
+    let ea_bytes = bootstrap_address_bytes(xprv.public(), payload);
 
     unsafe { write_data(&ea_bytes, out) }
 